@@ -0,0 +1,120 @@
+// Environment diagnostics ("doctor") command.
+//
+// Collects host environment details that need filesystem/PATH/process
+// access, which is slow or unavailable from the webview, so bug reports
+// carry reproducible context.
+
+use std::path::Path;
+use std::process::Command;
+
+use serde::Serialize;
+
+/// Package managers we know how to probe on PATH, along with the flag
+/// that prints a parseable version string.
+const KNOWN_PACKAGE_MANAGERS: &[(&str, &str)] = &[
+    ("apt", "--version"),
+    ("brew", "--version"),
+    ("winget", "--version"),
+    ("choco", "--version"),
+    ("dnf", "--version"),
+    ("pacman", "--version"),
+];
+
+#[derive(Debug, Serialize)]
+pub struct PackageManagerInfo {
+    name: String,
+    version: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RegistryReachability {
+    registry: String,
+    reachable: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EnvironmentInfo {
+    os_name: String,
+    os_version: String,
+    arch: String,
+    package_managers: Vec<PackageManagerInfo>,
+    runtime_version: Option<String>,
+    free_disk_space_bytes: Option<u64>,
+    registries: Vec<RegistryReachability>,
+}
+
+/// Collects a structured snapshot of the host environment for the
+/// diagnostics panel and for attaching to bug reports. Probing PATH,
+/// spawning `--version` child processes, and checking registry
+/// reachability all block, so the work runs on a blocking thread instead
+/// of the webview IPC thread.
+#[tauri::command]
+pub async fn environment_info(
+    install_target: String,
+    registries: Vec<String>,
+) -> Result<EnvironmentInfo, String> {
+    tauri::async_runtime::spawn_blocking(move || EnvironmentInfo {
+        os_name: os_info::get().os_type().to_string(),
+        os_version: os_info::get().version().to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        package_managers: detect_package_managers(),
+        runtime_version: detect_runtime_version(),
+        free_disk_space_bytes: free_disk_space(Path::new(&install_target)),
+        registries: registries
+            .into_iter()
+            .map(|registry| {
+                let reachable = registry_reachable(&registry);
+                RegistryReachability { registry, reachable }
+            })
+            .collect(),
+    })
+    .await
+    .map_err(|e| format!("environment probe task panicked: {e}"))
+}
+
+fn detect_package_managers() -> Vec<PackageManagerInfo> {
+    KNOWN_PACKAGE_MANAGERS
+        .iter()
+        .filter_map(|(name, version_flag)| {
+            let output = Command::new(name).arg(version_flag).output().ok()?;
+            if !output.status.success() {
+                return None;
+            }
+            let version = String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+            Some(PackageManagerInfo {
+                name: name.to_string(),
+                version,
+            })
+        })
+        .collect()
+}
+
+/// The JS runtime version powering `@opkg/core`, as reported by `node
+/// --version` (or `bun --version` when that's what's on PATH instead).
+fn detect_runtime_version() -> Option<String> {
+    for runtime in ["node", "bun"] {
+        if let Ok(output) = Command::new(runtime).arg("--version").output() {
+            if output.status.success() {
+                let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                return Some(format!("{runtime} {version}"));
+            }
+        }
+    }
+    None
+}
+
+fn free_disk_space(target: &Path) -> Option<u64> {
+    fs2::free_space(target).ok()
+}
+
+fn registry_reachable(registry: &str) -> bool {
+    ureq::head(registry)
+        .timeout(std::time::Duration::from_secs(3))
+        .call()
+        .is_ok()
+}
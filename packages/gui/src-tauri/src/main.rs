@@ -1,13 +1,38 @@
 // OpenPackage GUI - Tauri Backend
 //
-// This is the minimal Tauri shell. The actual business logic lives in
-// @opkg/core (TypeScript) and is invoked from the frontend via Tauri
-// commands that call into the JS runtime.
+// This is the Tauri shell. Most business logic still lives in @opkg/core
+// (TypeScript), but subsystems that need native process, filesystem, or
+// PATH access live here instead, so they stay off the webview's JS thread.
 
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod diagnostics;
+mod sidecar;
+mod transfer;
+mod updater;
+mod window_manager;
+
+use sidecar::SidecarState;
+
 fn main() {
     tauri::Builder::default()
+        .manage(SidecarState::default())
+        .invoke_handler(tauri::generate_handler![
+            sidecar::spawn_sidecar,
+            sidecar::cancel_sidecar,
+            updater::check_for_update,
+            updater::install_update,
+            diagnostics::environment_info,
+            window_manager::open_package_window,
+            window_manager::open_logs_window,
+            window_manager::focus_or_create,
+            transfer::download_and_extract,
+            transfer::download_and_extract_batch,
+        ])
+        .setup(|app| {
+            updater::check_on_startup(&app.handle());
+            Ok(())
+        })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,106 @@
+// Sidecar execution subsystem.
+//
+// Native package-manager binaries (apt, brew, winget, ...) are bundled as
+// Tauri sidecars pinned in the app bundle's `externalBin` and invoked from
+// here instead of the JS runtime, so long running installs stay off the
+// webview's event loop.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::api::process::{CommandChild, CommandEvent};
+use tauri::{AppHandle, Manager, State};
+
+/// Tracks running sidecar children by invocation id so they can be cancelled.
+#[derive(Default)]
+pub struct SidecarState(Mutex<HashMap<String, CommandChild>>);
+
+#[derive(Clone, Serialize)]
+struct SidecarOutput {
+    invocation_id: String,
+    line: String,
+}
+
+#[derive(Clone, Serialize)]
+struct SidecarExit {
+    invocation_id: String,
+    code: Option<i32>,
+}
+
+/// Spawns the bundled sidecar binary `name` (resolved from the app's
+/// `externalBin`, never the host `PATH`) with `args`, streaming its
+/// stdout/stderr back to the frontend as `sidecar://stdout` /
+/// `sidecar://stderr` events tagged with `invocation_id`. Returns
+/// immediately; completion is signalled via the `sidecar://exit` event.
+#[tauri::command]
+pub fn spawn_sidecar(
+    app: AppHandle,
+    state: State<SidecarState>,
+    invocation_id: String,
+    name: String,
+    args: Vec<String>,
+) -> Result<(), String> {
+    let (mut events, child) = tauri::api::process::Command::new_sidecar(&name)
+        .map_err(|e| format!("unknown sidecar `{name}`: {e}"))?
+        .args(&args)
+        .spawn()
+        .map_err(|e| format!("failed to spawn sidecar `{name}`: {e}"))?;
+
+    state.0.lock().unwrap().insert(invocation_id.clone(), child);
+
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = events.recv().await {
+            match event {
+                CommandEvent::Stdout(line) => {
+                    let _ = app.emit_all(
+                        "sidecar://stdout",
+                        SidecarOutput {
+                            invocation_id: invocation_id.clone(),
+                            line,
+                        },
+                    );
+                }
+                CommandEvent::Stderr(line) | CommandEvent::Error(line) => {
+                    let _ = app.emit_all(
+                        "sidecar://stderr",
+                        SidecarOutput {
+                            invocation_id: invocation_id.clone(),
+                            line,
+                        },
+                    );
+                }
+                CommandEvent::Terminated(payload) => {
+                    app.state::<SidecarState>()
+                        .0
+                        .lock()
+                        .unwrap()
+                        .remove(&invocation_id);
+                    let _ = app.emit_all(
+                        "sidecar://exit",
+                        SidecarExit {
+                            invocation_id: invocation_id.clone(),
+                            code: payload.code,
+                        },
+                    );
+                    break;
+                }
+                _ => {}
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Kills a running sidecar invocation, if still alive.
+#[tauri::command]
+pub fn cancel_sidecar(state: State<SidecarState>, invocation_id: String) -> Result<(), String> {
+    let mut children = state.0.lock().unwrap();
+    match children.remove(&invocation_id) {
+        Some(child) => child
+            .kill()
+            .map_err(|e| format!("failed to kill sidecar: {e}")),
+        None => Ok(()),
+    }
+}
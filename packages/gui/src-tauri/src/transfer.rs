@@ -0,0 +1,339 @@
+// Download + archive extraction pipeline.
+//
+// Streams package archives with resumable HTTP range requests, verifies a
+// SHA-256 as bytes arrive, and extracts tar/tar.gz/zip on a bounded worker
+// pool, so large installs don't block the JS runtime.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager};
+use tokio::sync::Semaphore;
+
+/// Caps how many downloads/extractions run concurrently during a batch
+/// install, so we don't saturate disk I/O or the network link.
+const MAX_CONCURRENT_JOBS: usize = 4;
+
+#[derive(Debug, Deserialize)]
+pub struct TransferJob {
+    pub url: String,
+    pub dest: String,
+    pub expected_hash: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransferResult {
+    pub url: String,
+    pub dest: String,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+struct TransferProgress {
+    url: String,
+    downloaded: u64,
+    total: Option<u64>,
+}
+
+#[derive(Clone, Serialize)]
+struct ExtractProgress {
+    url: String,
+    file: String,
+    files_done: usize,
+    files_total: usize,
+}
+
+/// Downloads and extracts a single job: `url` into `dest`, failing if the
+/// downloaded bytes don't hash to `expected_hash`.
+#[tauri::command]
+pub async fn download_and_extract(app: AppHandle, job: TransferJob) -> Result<(), String> {
+    run_job(&app, &job).await
+}
+
+/// Runs a batch of jobs with bounded concurrency, returning a result per
+/// job rather than failing the whole batch on the first error.
+#[tauri::command]
+pub async fn download_and_extract_batch(
+    app: AppHandle,
+    jobs: Vec<TransferJob>,
+) -> Vec<TransferResult> {
+    let semaphore = std::sync::Arc::new(Semaphore::new(MAX_CONCURRENT_JOBS));
+
+    let tasks: Vec<_> = jobs
+        .into_iter()
+        .map(|job| {
+            let app = app.clone();
+            let semaphore = semaphore.clone();
+            tauri::async_runtime::spawn(async move {
+                let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+                let result = run_job(&app, &job).await.err();
+                TransferResult {
+                    url: job.url,
+                    dest: job.dest,
+                    error: result,
+                }
+            })
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for task in tasks {
+        results.push(task.await.expect("transfer task panicked"));
+    }
+    results
+}
+
+async fn run_job(app: &AppHandle, job: &TransferJob) -> Result<(), String> {
+    let archive = download_with_hash_check(app, &job.url, &job.expected_hash).await?;
+    extract_archive(app, &job.url, &archive, Path::new(&job.dest))
+}
+
+/// Whether `response` is actually honoring our `Range: bytes={expected_start}-`
+/// request, i.e. a `206 Partial Content` whose `Content-Range` start matches.
+/// Servers that don't support ranges reply `200` with the full body instead.
+fn range_resumed_from(response: &reqwest::Response, expected_start: usize) -> bool {
+    if response.status() != reqwest::StatusCode::PARTIAL_CONTENT {
+        return false;
+    }
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_RANGE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(parse_content_range_start)
+        .map_or(false, |start| start == expected_start)
+}
+
+/// Parses the start offset out of a `Content-Range: bytes 1024-2047/4096` header.
+fn parse_content_range_start(value: &str) -> Option<usize> {
+    value.strip_prefix("bytes ")?.split(['-', '/']).next()?.parse().ok()
+}
+
+/// Downloads `url` with resumable range requests, hashing bytes as they
+/// arrive and failing fast if the final digest doesn't match.
+async fn download_with_hash_check(
+    app: &AppHandle,
+    url: &str,
+    expected_hash: &str,
+) -> Result<Vec<u8>, String> {
+    let client = reqwest::Client::new();
+    let mut downloaded: Vec<u8> = Vec::new();
+    let mut hasher = Sha256::new();
+    let mut total = None;
+
+    loop {
+        let resuming = !downloaded.is_empty();
+        let mut request = client.get(url);
+        if resuming {
+            request = request.header("Range", format!("bytes={}-", downloaded.len()));
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| format!("failed to download `{url}`: {e}"))?;
+
+        if resuming && !range_resumed_from(&response, downloaded.len()) {
+            // Server ignored the Range request and sent the full body again
+            // (or a different range) - restart the hash+buffer from scratch
+            // rather than corrupting the archive with duplicated bytes.
+            downloaded.clear();
+            hasher = Sha256::new();
+        }
+
+        if total.is_none() {
+            total = response.content_length();
+        }
+
+        use futures_util::StreamExt;
+        let mut stream = response.bytes_stream();
+        let mut interrupted = false;
+
+        while let Some(chunk) = stream.next().await {
+            match chunk {
+                Ok(chunk) => {
+                    hasher.update(&chunk);
+                    downloaded.extend_from_slice(&chunk);
+                    let _ = app.emit_all(
+                        "transfer://progress",
+                        TransferProgress {
+                            url: url.to_string(),
+                            downloaded: downloaded.len() as u64,
+                            total,
+                        },
+                    );
+                }
+                Err(_) => {
+                    interrupted = true;
+                    break;
+                }
+            }
+        }
+
+        if !interrupted {
+            break;
+        }
+        // Range request on the next loop iteration resumes from
+        // `downloaded.len()`.
+    }
+
+    let digest = format!("{:x}", hasher.finalize());
+    if digest != expected_hash {
+        return Err(format!(
+            "hash mismatch for `{url}`: expected {expected_hash}, got {digest}"
+        ));
+    }
+
+    Ok(downloaded)
+}
+
+/// Extracts `archive` into `dest`, picking tar/tar.gz/zip by sniffing the
+/// bytes, and fans file extraction out across a worker pool.
+fn extract_archive(app: &AppHandle, url: &str, archive: &[u8], dest: &Path) -> Result<(), String> {
+    std::fs::create_dir_all(dest).map_err(|e| format!("failed to create `{dest:?}`: {e}"))?;
+
+    if archive.starts_with(&[0x50, 0x4b, 0x03, 0x04]) {
+        extract_zip(app, url, archive, dest)
+    } else if archive.starts_with(&[0x1f, 0x8b]) {
+        extract_tar(app, url, flate2::read::GzDecoder::new(archive), dest)
+    } else {
+        extract_tar(app, url, archive, dest)
+    }
+}
+
+fn extract_zip(app: &AppHandle, url: &str, archive: &[u8], dest: &Path) -> Result<(), String> {
+    let mut zip = zip::ZipArchive::new(std::io::Cursor::new(archive))
+        .map_err(|e| format!("corrupt zip archive: {e}"))?;
+    let pool = threadpool::ThreadPool::new(num_cpus::get());
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut files_total = 0;
+
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i).map_err(|e| format!("corrupt zip entry: {e}"))?;
+        if entry.is_dir() {
+            continue;
+        }
+        let out_path: PathBuf = match entry.enclosed_name() {
+            Some(name) => dest.join(name),
+            None => continue,
+        };
+        let mode = entry.unix_mode();
+        let mut buf = Vec::new();
+        entry
+            .read_to_end(&mut buf)
+            .map_err(|e| format!("failed to read zip entry: {e}"))?;
+        let name = entry.name().to_string();
+        files_total += 1;
+        let tx = tx.clone();
+        pool.execute(move || {
+            let result = write_entry(&out_path, &buf, mode);
+            let _ = tx.send((name, result));
+        });
+    }
+    drop(tx);
+
+    for (files_done, (file, result)) in rx.into_iter().enumerate() {
+        result?;
+        let _ = app.emit_all(
+            "transfer://extract-progress",
+            ExtractProgress {
+                url: url.to_string(),
+                file,
+                files_done: files_done + 1,
+                files_total,
+            },
+        );
+    }
+
+    Ok(())
+}
+
+/// Mirrors zip's `enclosed_name()`: rejects absolute paths and `..`
+/// components so a malicious tarball entry can't escape `dest`.
+fn enclosed_tar_path(entry: &tar::Entry<impl Read>) -> Option<PathBuf> {
+    let path = entry.path().ok()?;
+    if path.is_absolute()
+        || path
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+    {
+        return None;
+    }
+    Some(path.into_owned())
+}
+
+fn extract_tar(
+    app: &AppHandle,
+    url: &str,
+    reader: impl Read,
+    dest: &Path,
+) -> Result<(), String> {
+    let mut tar = tar::Archive::new(reader);
+    let entries = tar.entries().map_err(|e| format!("corrupt tar archive: {e}"))?;
+
+    let pool = threadpool::ThreadPool::new(num_cpus::get());
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut files_total = 0;
+
+    for entry in entries {
+        let mut entry = entry.map_err(|e| format!("corrupt tar entry: {e}"))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let out_path = match enclosed_tar_path(&entry) {
+            Some(name) => dest.join(name),
+            None => continue,
+        };
+        let mode = entry.header().mode().ok();
+        let mut buf = Vec::new();
+        entry
+            .read_to_end(&mut buf)
+            .map_err(|e| format!("failed to read tar entry: {e}"))?;
+        let name = out_path.display().to_string();
+        files_total += 1;
+        let tx = tx.clone();
+        pool.execute(move || {
+            let result = write_entry(&out_path, &buf, mode);
+            let _ = tx.send((name, result));
+        });
+    }
+    drop(tx);
+
+    for (files_done, (file, result)) in rx.into_iter().enumerate() {
+        result?;
+        let _ = app.emit_all(
+            "transfer://extract-progress",
+            ExtractProgress {
+                url: url.to_string(),
+                file,
+                files_done: files_done + 1,
+                files_total,
+            },
+        );
+    }
+
+    Ok(())
+}
+
+fn write_entry(path: &Path, contents: &[u8], mode: Option<u32>) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(path, contents).map_err(|e| e.to_string())?;
+    set_unix_mode(path, mode)
+}
+
+/// Preserves the archive entry's Unix permission bits (notably `+x`) so
+/// extracted binaries remain executable. A no-op on non-Unix targets.
+#[cfg(unix)]
+fn set_unix_mode(path: &Path, mode: Option<u32>) -> Result<(), String> {
+    use std::os::unix::fs::PermissionsExt;
+    let Some(mode) = mode else { return Ok(()) };
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode)).map_err(|e| e.to_string())
+}
+
+#[cfg(not(unix))]
+fn set_unix_mode(_path: &Path, _mode: Option<u32>) -> Result<(), String> {
+    Ok(())
+}
@@ -0,0 +1,145 @@
+// Self-update subsystem for the OpenPackage GUI.
+//
+// Checks a release manifest for a newer signed build, verifies it against
+// an embedded minisign public key, and applies it without requiring the
+// user to reinstall.
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+/// Minisign public key for OpenPackage GUI releases. Keep in sync with the
+/// key used to sign artifacts in the release pipeline.
+const UPDATE_PUBLIC_KEY: &str = include_str!("../update_pubkey.txt");
+
+const MANIFEST_URL: &str = "https://updates.openpackage.dev/gui/latest.json";
+
+#[derive(Debug, Deserialize)]
+struct ReleaseManifest {
+    version: String,
+    notes: String,
+    url: String,
+    signature: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub notes: String,
+    url: String,
+    signature: String,
+}
+
+#[derive(Clone, Serialize)]
+struct UpdateProgress {
+    downloaded: u64,
+    total: Option<u64>,
+}
+
+/// Queries the release manifest and returns update info if a newer version
+/// than `current_version` is available.
+#[tauri::command]
+pub async fn check_for_update(current_version: String) -> Result<Option<UpdateInfo>, String> {
+    let manifest: ReleaseManifest = reqwest::get(MANIFEST_URL)
+        .await
+        .map_err(|e| format!("failed to fetch update manifest: {e}"))?
+        .json()
+        .await
+        .map_err(|e| format!("malformed update manifest: {e}"))?;
+
+    let latest = semver::Version::parse(&manifest.version)
+        .map_err(|e| format!("malformed version `{}` in update manifest: {e}", manifest.version))?;
+    let current = semver::Version::parse(&current_version)
+        .map_err(|e| format!("malformed current version `{current_version}`: {e}"))?;
+
+    if latest <= current {
+        return Ok(None);
+    }
+
+    Ok(Some(UpdateInfo {
+        version: manifest.version,
+        notes: manifest.notes,
+        url: manifest.url,
+        signature: manifest.signature,
+    }))
+}
+
+/// Downloads, verifies, and applies the update described by `info`, then
+/// relaunches the app. Emits `updater://progress` events while downloading.
+#[tauri::command]
+pub async fn install_update(app: AppHandle, info: UpdateInfo) -> Result<(), String> {
+    let archive = download_with_progress(&app, &info.url).await?;
+
+    verify_signature(&archive, &info.signature)?;
+
+    apply_update(&archive)?;
+
+    app.restart();
+    #[allow(unreachable_code)]
+    Ok(())
+}
+
+async fn download_with_progress(app: &AppHandle, url: &str) -> Result<Vec<u8>, String> {
+    let response = reqwest::get(url)
+        .await
+        .map_err(|e| format!("failed to download update: {e}"))?;
+    let total = response.content_length();
+
+    let mut downloaded = 0u64;
+    let mut bytes = Vec::new();
+    let mut stream = response.bytes_stream();
+
+    use futures_util::StreamExt;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("update download interrupted: {e}"))?;
+        downloaded += chunk.len() as u64;
+        bytes.extend_from_slice(&chunk);
+        let _ = app.emit_all("updater://progress", UpdateProgress { downloaded, total });
+    }
+
+    Ok(bytes)
+}
+
+fn verify_signature(archive: &[u8], signature: &str) -> Result<(), String> {
+    let public_key = minisign_verify::PublicKey::from_base64(UPDATE_PUBLIC_KEY.trim())
+        .map_err(|e| format!("invalid embedded update public key: {e}"))?;
+    let signature = minisign_verify::Signature::decode(signature)
+        .map_err(|e| format!("invalid update signature: {e}"))?;
+
+    public_key
+        .verify(archive, &signature, false)
+        .map_err(|_| "update signature verification failed".to_string())
+}
+
+/// Extracts the single platform binary from the downloaded tar.gz archive
+/// and atomically swaps it in for the currently running executable.
+fn apply_update(archive: &[u8]) -> Result<(), String> {
+    let decoder = flate2::read::GzDecoder::new(archive);
+    let mut tar = tar::Archive::new(decoder);
+
+    let staged = tempfile::NamedTempFile::new()
+        .map_err(|e| format!("failed to stage update: {e}"))?;
+
+    for entry in tar.entries().map_err(|e| format!("corrupt update archive: {e}"))? {
+        let mut entry = entry.map_err(|e| format!("corrupt update archive: {e}"))?;
+        if entry.header().entry_type().is_file() {
+            entry
+                .unpack(staged.path())
+                .map_err(|e| format!("failed to unpack update: {e}"))?;
+            break;
+        }
+    }
+
+    self_replace::self_replace(staged.path())
+        .map_err(|e| format!("failed to install update: {e}"))
+}
+
+/// Registered in `.setup()` to check for updates shortly after launch,
+/// without blocking the window from showing.
+pub fn check_on_startup(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Ok(Some(info)) = check_for_update(env!("CARGO_PKG_VERSION").to_string()).await {
+            let _ = app.emit_all("updater://available", info);
+        }
+    });
+}
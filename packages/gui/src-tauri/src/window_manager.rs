@@ -0,0 +1,80 @@
+// Window management for multi-window support.
+//
+// Package detail views and install logs live in their own native windows
+// instead of modals. Windows are tracked by label so repeated requests
+// for the same window focus it rather than creating a duplicate.
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager, WindowBuilder, WindowUrl};
+
+#[derive(Clone, Serialize)]
+struct WindowClosed {
+    label: String,
+}
+
+/// Focuses the window with `label` if it already exists, otherwise creates
+/// it pointed at `url` with `context` injected before any page script runs.
+///
+/// `context` is delivered via `initialization_script` rather than a
+/// `tauri://created` listener attached after `build()` returns: the window
+/// can finish creating (and the event can fire) before such a listener is
+/// registered, silently dropping the payload.
+pub fn focus_or_create_window(
+    app: &AppHandle,
+    label: &str,
+    url: &str,
+    context: impl Serialize,
+) -> Result<(), String> {
+    if let Some(window) = app.get_window(label) {
+        window.set_focus().map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+
+    let context_json = serde_json::to_string(&context).map_err(|e| e.to_string())?;
+    let init_script = format!("window.__OPKG_WINDOW_CONTEXT__ = {context_json};");
+
+    let window = WindowBuilder::new(app, label, WindowUrl::App(url.into()))
+        .initialization_script(&init_script)
+        .build()
+        .map_err(|e| format!("failed to create window `{label}`: {e}"))?;
+
+    let app_handle = app.clone();
+    let closed_label = label.to_string();
+    window.on_window_event(move |event| {
+        if let tauri::WindowEvent::Destroyed = event {
+            let _ = app_handle.emit_all(
+                "window-closed",
+                WindowClosed {
+                    label: closed_label.clone(),
+                },
+            );
+        }
+    });
+
+    Ok(())
+}
+
+/// Opens (or focuses) the detail window for `package_id`.
+#[tauri::command]
+pub fn open_package_window(app: AppHandle, package_id: String) -> Result<(), String> {
+    let label = format!("package-{package_id}");
+    focus_or_create_window(
+        &app,
+        &label,
+        "index.html#/package",
+        serde_json::json!({ "packageId": package_id }),
+    )
+}
+
+/// Opens (or focuses) the single shared logs window.
+#[tauri::command]
+pub fn open_logs_window(app: AppHandle) -> Result<(), String> {
+    focus_or_create_window(&app, "logs", "index.html#/logs", serde_json::json!({}))
+}
+
+/// Generic focus-or-create entry point for windows that don't need a
+/// dedicated command of their own.
+#[tauri::command]
+pub fn focus_or_create(app: AppHandle, label: String, url: String) -> Result<(), String> {
+    focus_or_create_window(&app, &label, &url, serde_json::json!({}))
+}